@@ -0,0 +1,200 @@
+//! A matrix of bits with a per-row sparse or dense representation.
+
+use alloc::vec::Vec;
+
+use bit_vec::BitVec;
+
+use crate::local_prelude::*;
+use crate::util::round_up_to_next;
+
+/// A matrix of bits that stores each row either as a sorted list of set column indices or,
+/// once the row grows dense enough, as a dense bit vector.
+///
+/// This mirrors the sparse/dense hybrid used for NLL bitsets: rows that hold only a handful
+/// of set bits avoid allocating a full `round_up_to_next(row_bits, BITS)` bits, while rows
+/// that fill up switch to the dense layout automatically. It exposes the subset of
+/// [`BitMatrix`](crate::BitMatrix)'s surface needed to use it as a drop-in for relations with
+/// many columns but few set bits per row.
+#[derive(Clone, Debug, Default)]
+pub struct HybridBitMatrix {
+    rows: Vec<Row>,
+    row_bits: usize,
+}
+
+/// The storage backing a single row.
+#[derive(Clone, Debug)]
+enum Row {
+    /// Sorted list of set column indices.
+    Sparse(Vec<usize>),
+    /// Dense bit vector, one bit per column.
+    Dense(BitVec),
+}
+
+impl HybridBitMatrix {
+    /// Creates a new matrix with the given number of rows and columns. Every row starts in
+    /// the sparse representation.
+    pub fn new(rows: usize, row_bits: usize) -> Self {
+        let mut row_vec = Vec::with_capacity(rows);
+        for _ in 0..rows {
+            row_vec.push(Row::Sparse(Vec::new()));
+        }
+        HybridBitMatrix {
+            rows: row_vec,
+            row_bits,
+        }
+    }
+
+    /// Returns the matrix's size as `(rows, columns)`.
+    pub fn size(&self) -> (usize, usize) {
+        (self.rows.len(), self.row_bits)
+    }
+
+    /// The largest number of set bits a sparse row holds; a row that grows past it is
+    /// promoted to the dense layout.
+    #[inline]
+    fn density_threshold(&self) -> usize {
+        round_up_to_next(self.row_bits, BITS) / BITS
+    }
+
+    /// Returns `true` if the bit at `(row, col)` is set.
+    pub fn get(&self, row: usize, col: usize) -> bool {
+        self.rows[row].get(col)
+    }
+
+    /// Sets the value of a bit, promoting the row to the dense layout if it crosses the
+    /// density threshold.
+    pub fn set(&mut self, row: usize, col: usize, enabled: bool) {
+        let threshold = self.density_threshold();
+        let row_bits = self.row_bits;
+        self.rows[row].set(col, enabled, row_bits, threshold);
+    }
+
+    /// Iterates over the set columns of a row in ascending order.
+    pub fn iter_row(&self, row: usize) -> impl Iterator<Item = usize> + '_ {
+        self.rows[row].iter()
+    }
+
+    /// Unions the set columns of `src` into `dst`, returning `true` if `dst` changed.
+    pub fn union_with(&mut self, dst: usize, src: usize) -> bool {
+        if dst == src {
+            return false;
+        }
+        let threshold = self.density_threshold();
+        let row_bits = self.row_bits;
+        let cols: Vec<usize> = self.rows[src].iter().collect();
+        let mut changed = false;
+        for col in cols {
+            changed |= self.rows[dst].set(col, true, row_bits, threshold);
+        }
+        changed
+    }
+
+    /// Computes the transitive closure of the binary relation represented by the matrix.
+    ///
+    /// Uses the Warshall's algorithm.
+    pub fn transitive_closure(&mut self) {
+        assert_eq!(self.rows.len(), self.row_bits);
+        let threshold = self.density_threshold();
+        let row_bits = self.row_bits;
+        for pos in 0..self.row_bits {
+            let cols: Vec<usize> = self.rows[pos].iter().collect();
+            for row in 0..self.rows.len() {
+                if row != pos && self.rows[row].get(pos) {
+                    for &col in &cols {
+                        self.rows[row].set(col, true, row_bits, threshold);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Row {
+    fn get(&self, col: usize) -> bool {
+        match self {
+            Row::Sparse(cols) => cols.binary_search(&col).is_ok(),
+            Row::Dense(bits) => bits.get(col).unwrap_or(false),
+        }
+    }
+
+    /// Sets `col` to `enabled`, returning `true` if the bit changed. A sparse row that grows
+    /// past `threshold` set bits is converted to the dense layout.
+    fn set(&mut self, col: usize, enabled: bool, row_bits: usize, threshold: usize) -> bool {
+        match self {
+            Row::Sparse(cols) => match cols.binary_search(&col) {
+                Ok(idx) => {
+                    if enabled {
+                        false
+                    } else {
+                        cols.remove(idx);
+                        true
+                    }
+                }
+                Err(idx) => {
+                    if enabled {
+                        cols.insert(idx, col);
+                        if cols.len() > threshold {
+                            self.densify(row_bits);
+                        }
+                        true
+                    } else {
+                        false
+                    }
+                }
+            },
+            Row::Dense(bits) => {
+                let old = bits.get(col).unwrap_or(false);
+                if old != enabled {
+                    bits.set(col, enabled);
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    /// Converts a sparse row to the dense representation.
+    fn densify(&mut self, row_bits: usize) {
+        if let Row::Sparse(cols) = self {
+            let mut bits = BitVec::from_elem(round_up_to_next(row_bits, BITS), false);
+            for &col in cols.iter() {
+                bits.set(col, true);
+            }
+            *self = Row::Dense(bits);
+        }
+    }
+
+    fn iter(&self) -> RowIter<'_> {
+        match self {
+            Row::Sparse(cols) => RowIter::Sparse(cols.iter()),
+            Row::Dense(bits) => RowIter::Dense { bits, pos: 0 },
+        }
+    }
+}
+
+/// An iterator over the set columns of a row.
+enum RowIter<'a> {
+    Sparse(core::slice::Iter<'a, usize>),
+    Dense { bits: &'a BitVec, pos: usize },
+}
+
+impl<'a> Iterator for RowIter<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        match self {
+            RowIter::Sparse(iter) => iter.next().copied(),
+            RowIter::Dense { bits, pos } => {
+                while *pos < bits.len() {
+                    let at = *pos;
+                    *pos += 1;
+                    if bits.get(at).unwrap_or(false) {
+                        return Some(at);
+                    }
+                }
+                None
+            }
+        }
+    }
+}
@@ -11,12 +11,16 @@
 #![cfg_attr(test, deny(warnings))]
 #![no_std]
 
+extern crate alloc;
+
 pub mod block;
+pub mod hybrid;
 pub mod matrix;
 pub mod row;
 pub mod submatrix;
 mod util;
 
+pub use hybrid::HybridBitMatrix;
 pub use matrix::BitMatrix;
 
 /// A value for borrowing.
@@ -148,6 +148,296 @@ impl BitMatrix {
             self.set(i, i, true);
         }
     }
+
+    /// Returns the transpose of the matrix, a `(row_bits, num_rows)` matrix whose `(c, r)` bit
+    /// equals this matrix's `(r, c)` bit.
+    ///
+    /// Unlike the closure operations, this places no constraint on the matrix's shape. The
+    /// pass is tiled: each group of up to `BITS` input rows is combined, one column block at a
+    /// time, into a `BITS x BITS` tile, transposed in place, and written out a whole block at
+    /// a time, so every output row in the group is filled together instead of being touched
+    /// one bit at a time.
+    pub fn transpose(&self) -> BitMatrix {
+        let rows = self.num_rows();
+        let mut out = BitMatrix::new(self.row_bits, rows);
+        let col_blocks = round_up_to_next(self.row_bits, BITS) / BITS;
+
+        let mut row_group = 0;
+        while row_group < rows {
+            let group_rows = cmp::min(BITS, rows - row_group);
+            let out_block = row_group / BITS;
+            for cb in 0..col_blocks {
+                let mut tile: [Block; BITS] = [0; BITS];
+                for k in 0..group_rows {
+                    tile[k] = self[row_group + k].slice[cb];
+                }
+                transpose_tile(&mut tile);
+                for b in 0..BITS {
+                    let c = cb * BITS + b;
+                    if c < self.row_bits {
+                        out[c].slice[out_block] = tile[b];
+                    }
+                }
+            }
+            row_group += BITS;
+        }
+        out
+    }
+
+    /// Unions `other` into the given row, returning `true` if the row changed.
+    pub fn union_with(&mut self, row: usize, other: &BitSlice) -> bool {
+        self[row].union_with(other)
+    }
+
+    /// Intersects the given row with `other`, returning `true` if the row changed.
+    pub fn intersect_with(&mut self, row: usize, other: &BitSlice) -> bool {
+        self[row].intersect_with(other)
+    }
+
+    /// Removes `other`'s bits from the given row, returning `true` if the row changed.
+    pub fn difference_with(&mut self, row: usize, other: &BitSlice) -> bool {
+        self[row].difference_with(other)
+    }
+
+    /// Toggles the given row against `other`, returning `true` if the row changed.
+    pub fn symmetric_difference_with(&mut self, row: usize, other: &BitSlice) -> bool {
+        self[row].symmetric_difference_with(other)
+    }
+
+    /// Counts the set bits in the whole matrix.
+    pub fn count_ones(&self) -> usize {
+        (0..self.num_rows())
+            .map(|row| self[row].count_ones(self.row_bits))
+            .sum()
+    }
+
+    /// Counts the set bits in a single row.
+    pub fn row_count_ones(&self, row: usize) -> usize {
+        self[row].count_ones(self.row_bits)
+    }
+
+    /// Multiplies two relations, combining partial products with `combine`.
+    ///
+    /// For each output cell `(i, k)` the value accumulates, over every `j` where `self[i][j]`
+    /// is set, the corresponding row `rhs[j]`. As in
+    /// [`transitive_closure`](Self::transitive_closure), the accumulation
+    /// is performed block at a time over the stored row slices.
+    fn product(&self, rhs: &BitMatrix, combine: fn(&mut Block, Block)) -> BitMatrix {
+        assert_eq!(self.row_bits, rhs.num_rows());
+        let rows = self.num_rows();
+        let mut out = BitMatrix::new(rows, rhs.row_bits);
+        for i in 0..rows {
+            for j in 0..self.row_bits {
+                if self[(i, j)] {
+                    for (dst, src) in out[i].iter_blocks_mut().zip(rhs[j].iter_blocks()) {
+                        combine(dst, *src);
+                    }
+                }
+            }
+        }
+        out
+    }
+
+    /// Computes the boolean matrix product, the OR-of-ANDs over the shared dimension.
+    ///
+    /// Output cell `(i, k)` is set when `self[i][j] & rhs[j][k]` holds for some `j`.
+    pub fn mul_bool(&self, rhs: &BitMatrix) -> BitMatrix {
+        self.product(rhs, |dst, src| *dst |= src)
+    }
+
+    /// Computes the matrix product over GF(2), the XOR-of-ANDs (parity) over the shared
+    /// dimension.
+    pub fn mul_gf2(&self, rhs: &BitMatrix) -> BitMatrix {
+        self.product(rhs, |dst, src| *dst ^= src)
+    }
+
+    /// Raises a square matrix to the `n`th power under the boolean product by repeated
+    /// squaring.
+    ///
+    /// `pow_bool(0)` is the identity relation.
+    pub fn pow_bool(&self, mut n: usize) -> BitMatrix {
+        assert_eq!(self.num_rows(), self.row_bits);
+        let size = self.row_bits;
+        let mut result = BitMatrix::new(size, size);
+        result.reflexive_closure();
+        let mut base = self.clone();
+        while n > 0 {
+            if n & 1 == 1 {
+                result = result.mul_bool(&base);
+            }
+            n >>= 1;
+            if n > 0 {
+                base = base.mul_bool(&base);
+            }
+        }
+        result
+    }
+
+    /// Swaps the contents of two rows.
+    #[inline]
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        if a == b {
+            return;
+        }
+        let row_size = round_up_to_next(self.row_bits, BITS) / BITS;
+        let (lo, hi) = if a < b { (a, b) } else { (b, a) };
+        let storage = unsafe { self.bit_vec.storage_mut() };
+        let (left, right) = storage.split_at_mut(hi * row_size);
+        left[lo * row_size..(lo + 1) * row_size].swap_with_slice(&mut right[..row_size]);
+    }
+
+    /// Reduces the matrix to row echelon form over GF(2), the two-element field in which
+    /// addition is `XOR` and multiplication is `AND`.
+    ///
+    /// Columns are processed left to right; for each column a pivot row is chosen from the
+    /// rows at or below the current pivot position, swapped into place, and then added into
+    /// every other row whose bit in that column is set. The block-wise XOR reuses the same
+    /// `iter_blocks_mut().zip(..)` loop as [`transitive_closure`](Self::transitive_closure),
+    /// so the elimination runs a word at a time. The result is in reduced form: each pivot
+    /// column holds a single set bit.
+    pub fn row_echelon(&mut self) {
+        let rows = self.num_rows();
+        let mut pivot = 0;
+        for col in 0..self.row_bits {
+            let sel = (pivot..rows).find(|&r| self[(r, col)]);
+            let pivot_row = match sel {
+                Some(r) => r,
+                None => continue,
+            };
+            self.swap_rows(pivot, pivot_row);
+            let (mut rows0, mut rows1a) = self.split_at_mut(pivot);
+            let (row, mut rows1b) = rows1a.split_at_mut(1);
+            for dst_row in rows0.iter_mut().chain(rows1b.iter_mut()) {
+                if dst_row[col] {
+                    for (dst, src) in dst_row.iter_blocks_mut().zip(row[0].iter_blocks()) {
+                        *dst ^= src;
+                    }
+                }
+            }
+            pivot += 1;
+            if pivot == rows {
+                break;
+            }
+        }
+    }
+
+    /// Returns the rank of the matrix over GF(2), i.e. the number of non-zero rows once the
+    /// matrix is reduced with [`row_echelon`](Self::row_echelon).
+    pub fn rank(&self) -> usize {
+        let mut reduced = self.clone();
+        reduced.row_echelon();
+        (0..reduced.num_rows())
+            .filter(|&r| reduced[r].iter_blocks().any(|&block| block != 0))
+            .count()
+    }
+
+    /// Solves the linear system `self * x = rhs` over GF(2).
+    ///
+    /// The right-hand side is read bit by bit as a column vector, one entry per row. Returns
+    /// a particular solution (free variables set to zero), or `None` when the system is
+    /// inconsistent — that is, when a row reduces to all zeros while its right-hand side bit
+    /// is set.
+    pub fn solve(&self, rhs: &BitSlice) -> Option<BitVec> {
+        let rows = self.num_rows();
+        let cols = self.row_bits;
+        let mut augmented = BitMatrix::new(rows, cols + 1);
+        for row in 0..rows {
+            for col in 0..cols {
+                if self[(row, col)] {
+                    augmented.set(row, col, true);
+                }
+            }
+            if rhs.get(row) {
+                augmented.set(row, cols, true);
+            }
+        }
+        augmented.row_echelon();
+        let mut solution = BitVec::from_elem(cols, false);
+        for row in 0..rows {
+            match (0..cols).find(|&col| augmented[(row, col)]) {
+                Some(col) => {
+                    if augmented[(row, cols)] {
+                        solution.set(col, true);
+                    }
+                }
+                None => {
+                    if augmented[(row, cols)] {
+                        return None;
+                    }
+                }
+            }
+        }
+        Some(solution)
+    }
+
+    /// Computes the inverse of a square matrix over GF(2), or `None` when the matrix is
+    /// singular.
+    ///
+    /// The matrix is augmented with an identity block and reduced; the inverse is read off
+    /// from the right half, which succeeds only when the left half reduces to the identity.
+    pub fn inverse(&self) -> Option<BitMatrix> {
+        let n = self.row_bits;
+        if self.num_rows() != n {
+            return None;
+        }
+        let mut augmented = BitMatrix::new(n, n * 2);
+        for row in 0..n {
+            for col in 0..n {
+                if self[(row, col)] {
+                    augmented.set(row, col, true);
+                }
+            }
+            augmented.set(row, n + row, true);
+        }
+        augmented.row_echelon();
+        for row in 0..n {
+            for col in 0..n {
+                if augmented[(row, col)] != (row == col) {
+                    return None;
+                }
+            }
+        }
+        let mut inverse = BitMatrix::new(n, n);
+        for row in 0..n {
+            for col in 0..n {
+                if augmented[(row, n + col)] {
+                    inverse.set(row, col, true);
+                }
+            }
+        }
+        Some(inverse)
+    }
+}
+
+/// Transposes a `BITS x BITS` bit matrix in place, where `tile[i]`'s bit `j` holds row `i`,
+/// column `j` on input and row `j`, column `i` on output.
+///
+/// This is the standard shift/mask in-place transpose (Hacker's Delight, "Transposing a Bit
+/// Matrix"): at each step it swaps blocks of bits `j` positions apart between rows `j`
+/// apart, halving the block width each round until single bits have swapped across every
+/// pair of rows. That algorithm numbers a row's bits from the most significant one down, the
+/// opposite of the rest of this crate's "bit `i` is column `i`" convention, so rows are
+/// bit-reversed going in and out to line the two conventions up.
+fn transpose_tile(tile: &mut [Block; BITS]) {
+    for row in tile.iter_mut() {
+        *row = row.reverse_bits();
+    }
+    let mut j = BITS / 2;
+    let mut m: Block = 0x0000_FFFF;
+    while j != 0 {
+        let mut k = 0;
+        while k < BITS {
+            let t = (tile[k] ^ (tile[k + j] >> j)) & m;
+            tile[k] ^= t;
+            tile[k + j] ^= t << j;
+            k = (k + j + 1) & !j;
+        }
+        j >>= 1;
+        m ^= m << j;
+    }
+    for row in tile.iter_mut() {
+        *row = row.reverse_bits();
+    }
 }
 
 /// Returns the matrix's row in the form of an immutable slice.
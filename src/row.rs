@@ -55,6 +55,95 @@ impl BitSlice {
         }
     }
 
+    /// Counts the set bits among the first `len` bits of the slice.
+    ///
+    /// The final, possibly partial block is masked down to `len` so that padding bits past
+    /// the row's width are not counted.
+    pub fn count_ones(&self, len: usize) -> usize {
+        let full = len / BITS;
+        let rem = len % BITS;
+        let mut count = 0;
+        for (i, &block) in self.iter_blocks().enumerate() {
+            if i < full {
+                count += block.count_ones() as usize;
+            } else if i == full {
+                if rem != 0 {
+                    count += (block & ((1 << rem) - 1)).count_ones() as usize;
+                }
+                break;
+            } else {
+                break;
+            }
+        }
+        count
+    }
+
+    /// Adds another row's bits into this one with `|=`, returning `true` if any bit changed.
+    #[inline]
+    pub fn union_with(&mut self, other: &BitSlice) -> bool {
+        let mut changed = false;
+        for (dst, src) in self.iter_blocks_mut().zip(other.iter_blocks()) {
+            let old = *dst;
+            *dst |= src;
+            changed |= *dst != old;
+        }
+        changed
+    }
+
+    /// Restricts this row to the bits it shares with another with `&=`, returning `true` if
+    /// any bit changed.
+    #[inline]
+    pub fn intersect_with(&mut self, other: &BitSlice) -> bool {
+        let mut changed = false;
+        for (dst, src) in self.iter_blocks_mut().zip(other.iter_blocks()) {
+            let old = *dst;
+            *dst &= src;
+            changed |= *dst != old;
+        }
+        changed
+    }
+
+    /// Removes another row's bits from this one with `&= !`, returning `true` if any bit
+    /// changed.
+    #[inline]
+    pub fn difference_with(&mut self, other: &BitSlice) -> bool {
+        let mut changed = false;
+        for (dst, src) in self.iter_blocks_mut().zip(other.iter_blocks()) {
+            let old = *dst;
+            *dst &= !src;
+            changed |= *dst != old;
+        }
+        changed
+    }
+
+    /// Toggles this row's bits against another with `^=`, returning `true` if any bit changed.
+    #[inline]
+    pub fn symmetric_difference_with(&mut self, other: &BitSlice) -> bool {
+        let mut changed = false;
+        for (dst, src) in self.iter_blocks_mut().zip(other.iter_blocks()) {
+            let old = *dst;
+            *dst ^= src;
+            changed |= *dst != old;
+        }
+        changed
+    }
+
+    /// Iterates over the indices of the set bits among the first `len` bits.
+    ///
+    /// Each block is walked by repeatedly taking its lowest set bit, so the cost is
+    /// proportional to the number of set bits rather than to `len`.
+    #[inline]
+    pub fn iter_ones(&self, len: usize) -> Ones<'_> {
+        let mut ones = Ones {
+            slice: self,
+            len,
+            block: 0,
+            remaining: 0,
+        };
+        ones.load();
+        ones
+    }
+
     /// Returns a small integer-sized slice of the bit vector slice.
     #[inline]
     pub fn small_slice_aligned(&self, bit: usize, len: u8) -> u32 {
@@ -89,6 +178,50 @@ impl Index<usize> for BitSlice {
     }
 }
 
+/// An iterator over the indices of a slice's set bits, yielded in ascending order.
+#[derive(Clone, Copy)]
+pub struct Ones<'a> {
+    slice: &'a BitSlice,
+    len: usize,
+    block: usize,
+    remaining: Block,
+}
+
+impl<'a> Ones<'a> {
+    /// Loads the current block, masking off bits past `len`.
+    #[inline]
+    fn load(&mut self) {
+        let start = self.block * BITS;
+        let word = self.slice.slice.get(self.block).copied().unwrap_or(0);
+        let rem = self.len - start;
+        self.remaining = if rem >= BITS {
+            word
+        } else {
+            word & ((1 << rem) - 1)
+        };
+    }
+}
+
+impl<'a> Iterator for Ones<'a> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        loop {
+            if self.remaining != 0 {
+                let bit = self.remaining.trailing_zeros() as usize;
+                self.remaining &= self.remaining - 1;
+                return Some(self.block * BITS + bit);
+            }
+            self.block += 1;
+            if self.block * BITS >= self.len {
+                return None;
+            }
+            self.load();
+        }
+    }
+}
+
 /// An iterator for `BitVecSlice`.
 #[derive(Clone)]
 pub struct Iter<'a> {
@@ -162,6 +162,26 @@ impl<'a> BitSubMatrixMut<'a> {
         }
     }
 
+    /// Unions `other` into the given row, returning `true` if the row changed.
+    pub fn union_with(&mut self, row: usize, other: &BitSlice) -> bool {
+        self[row].union_with(other)
+    }
+
+    /// Intersects the given row with `other`, returning `true` if the row changed.
+    pub fn intersect_with(&mut self, row: usize, other: &BitSlice) -> bool {
+        self[row].intersect_with(other)
+    }
+
+    /// Removes `other`'s bits from the given row, returning `true` if the row changed.
+    pub fn difference_with(&mut self, row: usize, other: &BitSlice) -> bool {
+        self[row].difference_with(other)
+    }
+
+    /// Toggles the given row against `other`, returning `true` if the row changed.
+    pub fn symmetric_difference_with(&mut self, row: usize, other: &BitSlice) -> bool {
+        self[row].symmetric_difference_with(other)
+    }
+
     /// Iterates over the matrix's rows in the form of mutable slices.
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut BitSlice> {
         fn f(arg: &mut [Block]) -> &mut BitSlice {
@@ -0,0 +1,14 @@
+use bit_matrix::BitMatrix;
+
+/// Builds a `BitMatrix` from a grid of 0/1 bytes, one row per slice.
+pub fn from_rows(rows: &[&[u8]]) -> BitMatrix {
+    let mut matrix = BitMatrix::new(rows.len(), rows[0].len());
+    for (i, row) in rows.iter().enumerate() {
+        for (j, &bit) in row.iter().enumerate() {
+            if bit != 0 {
+                matrix.set(i, j, true);
+            }
+        }
+    }
+    matrix
+}
@@ -0,0 +1,39 @@
+use bit_matrix::row::BitSlice;
+use bit_matrix::BitMatrix;
+
+#[test]
+fn test_slice_count_ones_masks_padding() {
+    // Bits past the requested length must not be counted.
+    let blocks = [0xFFFF_FFFFu32];
+    let slice = BitSlice::new(&blocks);
+    assert_eq!(slice.count_ones(5), 5);
+    assert_eq!(slice.count_ones(32), 32);
+}
+
+#[test]
+fn test_iter_ones_yields_indices() {
+    let blocks = [0b1001_0010u32];
+    let slice = BitSlice::new(&blocks);
+    let ones: Vec<usize> = slice.iter_ones(8).collect();
+    assert_eq!(ones, vec![1, 4, 7]);
+}
+
+#[test]
+fn test_iter_ones_respects_len() {
+    let blocks = [0b1001_0010u32];
+    let slice = BitSlice::new(&blocks);
+    let ones: Vec<usize> = slice.iter_ones(5).collect();
+    assert_eq!(ones, vec![1, 4]);
+}
+
+#[test]
+fn test_matrix_count_ones() {
+    let mut matrix = BitMatrix::new(3, 40);
+    matrix.set(0, 0, true);
+    matrix.set(0, 39, true);
+    matrix.set(2, 5, true);
+    assert_eq!(matrix.count_ones(), 3);
+    assert_eq!(matrix.row_count_ones(0), 2);
+    assert_eq!(matrix.row_count_ones(1), 0);
+    assert_eq!(matrix.row_count_ones(2), 1);
+}
@@ -0,0 +1,63 @@
+use bit_matrix::row::BitSlice;
+use bit_matrix::BitMatrix;
+
+mod common;
+use common::from_rows;
+
+#[test]
+fn test_rank_of_singular_matrix() {
+    // The third row is the sum of the first two, so the rank is 2.
+    let matrix = from_rows(&[&[1, 1, 0], &[0, 1, 1], &[1, 0, 1]]);
+    assert_eq!(matrix.rank(), 2);
+}
+
+#[test]
+fn test_rank_spans_multiple_blocks() {
+    // 40 columns cross two 32-bit blocks; row 2 is the XOR of rows 0 and 1, so eliminating it
+    // exercises the block-wise XOR across both blocks, and the rank is still 2.
+    let mut matrix = BitMatrix::new(3, 40);
+    matrix.set(0, 0, true);
+    matrix.set(0, 35, true);
+    matrix.set(1, 1, true);
+    matrix.set(1, 36, true);
+    matrix.set(2, 0, true);
+    matrix.set(2, 1, true);
+    matrix.set(2, 35, true);
+    matrix.set(2, 36, true);
+    assert_eq!(matrix.rank(), 2);
+}
+
+#[test]
+fn test_solve_unique() {
+    let matrix = from_rows(&[&[1, 0, 1], &[0, 1, 1], &[0, 0, 1]]);
+    // Right-hand side (0, 1, 1) read low bit first.
+    let blocks = [0b110u32];
+    let rhs = BitSlice::new(&blocks);
+    let solution = matrix.solve(rhs).unwrap();
+    assert_eq!(solution.get(0), Some(true));
+    assert_eq!(solution.get(1), Some(false));
+    assert_eq!(solution.get(2), Some(true));
+}
+
+#[test]
+fn test_solve_inconsistent() {
+    // Two equal rows with conflicting right-hand sides.
+    let matrix = from_rows(&[&[1, 0], &[1, 0]]);
+    let blocks = [0b10u32];
+    let rhs = BitSlice::new(&blocks);
+    assert!(matrix.solve(rhs).is_none());
+}
+
+#[test]
+fn test_inverse_round_trip() {
+    let matrix = from_rows(&[&[1, 0, 1], &[0, 1, 1], &[0, 0, 1]]);
+    let inverse = matrix.inverse().unwrap();
+    // Inverting the inverse yields the original matrix.
+    assert_eq!(inverse.inverse().unwrap(), matrix);
+}
+
+#[test]
+fn test_inverse_of_singular_is_none() {
+    let matrix = from_rows(&[&[1, 1, 0], &[0, 1, 1], &[1, 0, 1]]);
+    assert!(matrix.inverse().is_none());
+}
@@ -0,0 +1,58 @@
+use bit_matrix::HybridBitMatrix;
+
+#[test]
+fn test_set_get_sparse() {
+    let mut matrix = HybridBitMatrix::new(3, 10_000);
+    matrix.set(0, 42, true);
+    matrix.set(0, 9_999, true);
+    assert_eq!(matrix.get(0, 42), true);
+    assert_eq!(matrix.get(0, 9_999), true);
+    assert_eq!(matrix.get(0, 0), false);
+    assert_eq!(matrix.get(1, 42), false);
+    let ones: Vec<usize> = matrix.iter_row(0).collect();
+    assert_eq!(ones, vec![42, 9_999]);
+}
+
+#[test]
+fn test_clearing_bit() {
+    let mut matrix = HybridBitMatrix::new(1, 64);
+    matrix.set(0, 3, true);
+    assert_eq!(matrix.get(0, 3), true);
+    matrix.set(0, 3, false);
+    assert_eq!(matrix.get(0, 3), false);
+    let ones: Vec<usize> = matrix.iter_row(0).collect();
+    assert!(ones.is_empty());
+}
+
+#[test]
+fn test_densify_preserves_bits() {
+    // Cross the density threshold and make sure every bit survives the conversion.
+    let mut matrix = HybridBitMatrix::new(1, 64);
+    for col in 0..40 {
+        matrix.set(0, col, true);
+    }
+    let ones: Vec<usize> = matrix.iter_row(0).collect();
+    assert_eq!(ones, (0..40).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_union_with() {
+    let mut matrix = HybridBitMatrix::new(2, 64);
+    matrix.set(0, 1, true);
+    matrix.set(1, 2, true);
+    assert_eq!(matrix.union_with(0, 1), true);
+    assert_eq!(matrix.get(0, 2), true);
+    assert_eq!(matrix.union_with(0, 1), false);
+}
+
+#[test]
+fn test_transitive_closure() {
+    // A chain 0 -> 1 -> 2 closes to reach 2 from 0.
+    let mut matrix = HybridBitMatrix::new(3, 3);
+    matrix.set(0, 1, true);
+    matrix.set(1, 2, true);
+    matrix.transitive_closure();
+    assert_eq!(matrix.get(0, 1), true);
+    assert_eq!(matrix.get(0, 2), true);
+    assert_eq!(matrix.get(1, 2), true);
+}
@@ -0,0 +1,50 @@
+use bit_matrix::BitMatrix;
+
+mod common;
+use common::from_rows;
+
+#[test]
+fn test_mul_bool_paths_of_length_two() {
+    // Edges 0 -> 1 and 1 -> 2; the product exposes the length-two path 0 -> 2.
+    let adjacency = from_rows(&[&[0, 1, 0], &[0, 0, 1], &[0, 0, 0]]);
+    let squared = adjacency.mul_bool(&adjacency);
+    assert_eq!(squared, from_rows(&[&[0, 0, 1], &[0, 0, 0], &[0, 0, 0]]));
+}
+
+#[test]
+fn test_mul_gf2_counts_parity() {
+    // Two distinct length-two walks from 0 to 3 cancel under parity.
+    let adjacency = from_rows(&[
+        &[0, 1, 1, 0],
+        &[0, 0, 0, 1],
+        &[0, 0, 0, 1],
+        &[0, 0, 0, 0],
+    ]);
+    let squared = adjacency.mul_gf2(&adjacency);
+    assert_eq!(squared[(0, 3)], false);
+}
+
+#[test]
+fn test_pow_bool_zero_is_identity() {
+    let adjacency = from_rows(&[&[0, 1, 0], &[0, 0, 1], &[1, 0, 0]]);
+    let mut identity = BitMatrix::new(3, 3);
+    identity.reflexive_closure();
+    assert_eq!(adjacency.pow_bool(0), identity);
+}
+
+#[test]
+fn test_pow_bool_matches_repeated_product() {
+    let adjacency = from_rows(&[&[0, 1, 0], &[0, 0, 1], &[0, 0, 0]]);
+    let cubed = adjacency
+        .mul_bool(&adjacency)
+        .mul_bool(&adjacency);
+    assert_eq!(adjacency.pow_bool(3), cubed);
+}
+
+#[test]
+#[should_panic]
+fn test_mul_bool_panics_on_shared_dimension_mismatch() {
+    let lhs = from_rows(&[&[0, 1, 0], &[0, 0, 1]]);
+    let rhs = from_rows(&[&[1, 0], &[0, 1]]);
+    lhs.mul_bool(&rhs);
+}
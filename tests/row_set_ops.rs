@@ -0,0 +1,51 @@
+use bit_matrix::BitMatrix;
+
+#[test]
+fn test_union_reports_change() {
+    let mut matrix = BitMatrix::new(2, 8);
+    matrix.set(1, 2, true);
+    let other = {
+        let mut m = BitMatrix::new(1, 8);
+        m.set(0, 5, true);
+        m
+    };
+    assert_eq!(matrix.union_with(0, &other[0]), true);
+    // Unioning the same bits again changes nothing.
+    assert_eq!(matrix.union_with(0, &other[0]), false);
+    assert_eq!(matrix[(0, 5)], true);
+}
+
+#[test]
+fn test_intersect_and_difference() {
+    let mut matrix = BitMatrix::new(1, 8);
+    matrix.set(0, 1, true);
+    matrix.set(0, 2, true);
+    matrix.set(0, 3, true);
+    let mask = {
+        let mut m = BitMatrix::new(1, 8);
+        m.set(0, 2, true);
+        m.set(0, 3, true);
+        m
+    };
+    assert_eq!(matrix.intersect_with(0, &mask[0]), true);
+    assert_eq!(matrix.row_count_ones(0), 2);
+    assert_eq!(matrix.difference_with(0, &mask[0]), true);
+    assert_eq!(matrix.row_count_ones(0), 0);
+    // A difference against disjoint bits leaves the row untouched.
+    assert_eq!(matrix.difference_with(0, &mask[0]), false);
+}
+
+#[test]
+fn test_symmetric_difference() {
+    let mut matrix = BitMatrix::new(1, 8);
+    matrix.set(0, 1, true);
+    let other = {
+        let mut m = BitMatrix::new(1, 8);
+        m.set(0, 1, true);
+        m.set(0, 4, true);
+        m
+    };
+    assert_eq!(matrix.symmetric_difference_with(0, &other[0]), true);
+    assert_eq!(matrix[(0, 1)], false);
+    assert_eq!(matrix[(0, 4)], true);
+}
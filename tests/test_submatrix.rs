@@ -24,3 +24,38 @@ fn test_submatrix() {
     assert_eq!(iter.next().unwrap().get(2), false);
     assert_eq!(iter.next().unwrap().small_slice_aligned(1, 3), 0b101);
 }
+
+#[test]
+fn test_submatrix_mut_set_ops() {
+    let mut matrix = BitMatrix::new(4, 8);
+    matrix.set(1, 2, true);
+    matrix.set(1, 3, true);
+    matrix.set(3, 5, true);
+    let other = {
+        let mut m = BitMatrix::new(1, 8);
+        m.set(0, 3, true);
+        m.set(0, 4, true);
+        m
+    };
+
+    // Row 0 of `lower` is row 1 of the original matrix.
+    let (_, mut lower) = matrix.split_at_mut(1);
+    assert_eq!(lower.union_with(0, &other[0]), true);
+    assert_eq!(lower[0].get(4), true);
+
+    assert_eq!(lower.intersect_with(0, &other[0]), true);
+    assert_eq!(lower[0].get(2), false);
+    assert_eq!(lower[0].get(3), true);
+
+    assert_eq!(lower.difference_with(0, &other[0]), true);
+    assert_eq!(lower[0].get(3), false);
+    assert_eq!(lower[0].get(4), false);
+    // A difference against disjoint bits leaves the row untouched.
+    assert_eq!(lower.difference_with(0, &other[0]), false);
+
+    lower.set(0, 6, true);
+    assert_eq!(lower.symmetric_difference_with(0, &other[0]), true);
+    assert_eq!(lower[0].get(3), true);
+    assert_eq!(lower[0].get(4), true);
+    assert_eq!(lower[0].get(6), true);
+}
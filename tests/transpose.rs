@@ -0,0 +1,56 @@
+use bit_matrix::BitMatrix;
+
+mod common;
+use common::from_rows;
+
+#[test]
+fn test_transpose_rectangular() {
+    let matrix = from_rows(&[&[1, 0, 1], &[0, 1, 1]]);
+    let transposed = matrix.transpose();
+    assert_eq!(transposed.size(), (3, 2));
+    let expected = from_rows(&[&[1, 0], &[0, 1], &[1, 1]]);
+    assert_eq!(transposed, expected);
+}
+
+#[test]
+fn test_transpose_is_involutive() {
+    let matrix = from_rows(&[&[1, 0, 1], &[0, 1, 1]]);
+    assert_eq!(matrix.transpose().transpose(), matrix);
+}
+
+#[test]
+fn test_transpose_wide_row() {
+    // A single row wider than one block transposes to a tall single-column matrix.
+    let mut matrix = BitMatrix::new(1, 40);
+    matrix.set(0, 0, true);
+    matrix.set(0, 39, true);
+    let transposed = matrix.transpose();
+    assert_eq!(transposed.size(), (40, 1));
+    assert_eq!(transposed[(0, 0)], true);
+    assert_eq!(transposed[(39, 0)], true);
+    assert_eq!(transposed[(1, 0)], false);
+}
+
+#[test]
+fn test_transpose_spans_multiple_tiles() {
+    // 50 rows and 40 columns cross both the BITS-row-group boundary (one full 32-row group
+    // plus a partial one) and the BITS-column-block boundary that the tiled pass handles
+    // separately, so every cell is checked against the input bit by bit.
+    let rows = 50;
+    let cols = 40;
+    let mut matrix = BitMatrix::new(rows, cols);
+    for r in 0..rows {
+        for c in 0..cols {
+            if (r * 7 + c * 3) % 5 == 0 {
+                matrix.set(r, c, true);
+            }
+        }
+    }
+    let transposed = matrix.transpose();
+    assert_eq!(transposed.size(), (cols, rows));
+    for r in 0..rows {
+        for c in 0..cols {
+            assert_eq!(transposed[(c, r)], matrix[(r, c)]);
+        }
+    }
+}